@@ -1,11 +1,13 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
-use tauri::Manager;
+use tauri::{Emitter, Manager};
 use tauri_plugin_shell::process::CommandEvent;
 use tauri_plugin_shell::ShellExt;
 
+use std::io::Write;
 use std::net::TcpListener;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Mutex;
 use std::time::Duration;
 
@@ -19,11 +21,114 @@ const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_millis(3000);
 /// port keeps the OAuth callback URL consistent across launches, which avoids
 /// 409 conflicts on providers like OpenRouter that auto-register apps by origin.
 const PREFERRED_PORTS: &[u16] = &[4000, 3000, 5173];
+/// Base delay before the first supervised restart attempt.
+const SUPERVISOR_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Upper bound on the backoff delay between supervised restart attempts.
+const SUPERVISOR_MAX_DELAY: Duration = Duration::from_secs(30);
+/// Give up supervising after this many consecutive restart failures.
+const SUPERVISOR_MAX_RESTARTS: u32 = 5;
+/// Rotate the sidecar log file once it grows past this size.
+const SIDECAR_LOG_MAX_BYTES: u64 = 5 * 1024 * 1024;
+/// Number of rotated sidecar log files to keep around, besides the active one.
+const SIDECAR_LOG_MAX_FILES: usize = 5;
 
 struct AppState {
     sidecar_child: Mutex<Option<SidecarProcess>>,
+    /// Port recorded by `start_server` and at launch once the sidecar is
+    /// ready; cleared when the sidecar is stopped or exits. A supervised
+    /// restart does not record it again, and `restart_server` does not read
+    /// it (it picks a port through `find_free_port`).
+    current_port: Mutex<Option<u16>>,
+    /// Consecutive sidecar restart attempts since the last success, used to
+    /// compute supervisor backoff and when to give up.
+    restart_attempts: AtomicU32,
+    /// Bumped by every manual `start_server`/`stop_server` (and therefore
+    /// `restart_server`) call. A sidecar spawn stamps the generation that
+    /// was current when it started onto its own exit-supervision task; if
+    /// the generation has moved on by the time that task's process exits,
+    /// a manual stop or restart has already superseded it, so the
+    /// supervisor must stand down instead of resurrecting a process the
+    /// user just told us to stop.
+    user_generation: AtomicU32,
+    /// Identifies the current sidecar instance. Bumped whenever a sidecar is
+    /// deliberately retired (by `kill_sidecar` or a supervised restart) so a
+    /// `Terminated` event for an already-retired instance can tell it's
+    /// stale and stand down instead of clobbering state that may already
+    /// belong to whatever replaced it.
+    spawn_id: AtomicU32,
     /// Set once shutdown has been initiated to avoid double-kill.
     shutting_down: AtomicBool,
+    /// Rotating file the sidecar's stdout/stderr lines are persisted to.
+    /// Opened when the first sidecar is spawned, since it needs the app's
+    /// log dir.
+    log_writer: Mutex<Option<RotatingLogWriter>>,
+}
+
+/// A sidecar output line tagged with channel and timestamp, emitted to the
+/// webview as a Tauri event.
+#[derive(Clone, serde::Serialize)]
+struct SidecarLogEvent {
+    channel: &'static str,
+    line: String,
+    timestamp: String,
+}
+
+/// Persists sidecar log lines to disk, rotating by size and keeping the
+/// last `SIDECAR_LOG_MAX_FILES` rotated files besides the active one.
+struct RotatingLogWriter {
+    dir: PathBuf,
+    file: std::fs::File,
+    bytes_written: u64,
+}
+
+impl RotatingLogWriter {
+    fn open(dir: &Path) -> std::io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join("sidecar.log"))?;
+        let bytes_written = file.metadata()?.len();
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            file,
+            bytes_written,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) {
+        if self.bytes_written >= SIDECAR_LOG_MAX_BYTES {
+            if let Err(e) = self.rotate() {
+                eprintln!("Failed to rotate sidecar log: {e}");
+            }
+        }
+        if let Err(e) = writeln!(self.file, "{line}") {
+            eprintln!("Failed to write sidecar log: {e}");
+            return;
+        }
+        self.bytes_written += line.len() as u64 + 1;
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        let _ = std::fs::remove_file(
+            self.dir
+                .join(format!("sidecar.log.{SIDECAR_LOG_MAX_FILES}")),
+        );
+        for i in (1..SIDECAR_LOG_MAX_FILES).rev() {
+            let _ = std::fs::rename(
+                self.dir.join(format!("sidecar.log.{i}")),
+                self.dir.join(format!("sidecar.log.{}", i + 1)),
+            );
+        }
+        std::fs::rename(self.dir.join("sidecar.log"), self.dir.join("sidecar.log.1"))?;
+
+        self.file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join("sidecar.log"))?;
+        self.bytes_written = 0;
+        Ok(())
+    }
 }
 
 struct SidecarProcess {
@@ -57,8 +162,8 @@ fn open_in_system_browser(url: &str) {
     }
 }
 
-/// Attempts graceful sidecar shutdown, then falls back to a hard kill.
-/// Idempotent — only the first caller actually performs the shutdown.
+/// Kills the running sidecar, if any, unless a shutdown is already in
+/// progress. Idempotent — only the first caller actually performs it.
 fn kill_sidecar(app: &tauri::AppHandle) {
     let Some(state) = app.try_state::<AppState>() else {
         return;
@@ -69,13 +174,29 @@ fn kill_sidecar(app: &tauri::AppHandle) {
         return;
     }
 
+    retire_current_sidecar(&state);
+}
+
+/// Takes the current sidecar out of `AppState` and terminates it. Bumps
+/// `spawn_id` first so a `Terminated` event racing in for the outgoing
+/// process recognizes it's already been retired and stands down instead of
+/// touching state that may already belong to whatever replaces it.
+fn retire_current_sidecar(state: &AppState) {
+    state.spawn_id.fetch_add(1, Ordering::SeqCst);
+
     let Ok(mut guard) = state.sidecar_child.lock() else {
         return;
     };
     let Some(mut process) = guard.take() else {
         return;
     };
+    drop(guard);
 
+    terminate_sidecar_process(&mut process);
+}
+
+/// Attempts graceful shutdown of `process`, then falls back to a hard kill.
+fn terminate_sidecar_process(process: &mut SidecarProcess) {
     if let Some(pid) = process.pid {
         println!("Attempting graceful shutdown of sidecar (PID: {pid})...");
 
@@ -139,6 +260,75 @@ fn kill_sidecar(app: &tauri::AppHandle) {
     }
 }
 
+/// Starts the Burrito sidecar if it isn't already running and waits for it
+/// to become ready, returning the port the webview should (re)navigate to.
+#[tauri::command]
+fn start_server(app: tauri::AppHandle) -> Result<u16, String> {
+    let state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "AppState is not managed".to_string())?;
+
+    {
+        let guard = state
+            .sidecar_child
+            .lock()
+            .map_err(|_| "sidecar state lock poisoned".to_string())?;
+        if guard.is_some() {
+            return Err("Server is already running".to_string());
+        }
+    }
+
+    // Supersede whatever the exit-supervisor was doing for any previous
+    // sidecar: this is a deliberate, user-initiated (re)start.
+    state.user_generation.fetch_add(1, Ordering::SeqCst);
+
+    let port = find_free_port()?;
+    spawn_sidecar(&app, port)?;
+    check_server_started(port)?;
+
+    if let Ok(mut guard) = state.current_port.lock() {
+        *guard = Some(port);
+    }
+    // A fresh sidecar is running again; clear the flag so future shutdowns
+    // (and the supervisor) don't think we're already mid-teardown.
+    state.shutting_down.store(false, Ordering::SeqCst);
+    state.restart_attempts.store(0, Ordering::SeqCst);
+
+    Ok(port)
+}
+
+/// Stops the running sidecar but leaves the app itself open. Reuses the
+/// graceful-then-hard-kill logic in `kill_sidecar`.
+#[tauri::command]
+fn stop_server(app: tauri::AppHandle) -> Result<(), String> {
+    if let Some(state) = app.try_state::<AppState>() {
+        // Supersede the exit-supervisor *before* killing the process, so its
+        // exit-supervision task sees this generation has moved on and
+        // stands down instead of resurrecting the sidecar we just stopped.
+        state.user_generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    kill_sidecar(&app);
+
+    if let Some(state) = app.try_state::<AppState>() {
+        if let Ok(mut guard) = state.current_port.lock() {
+            *guard = None;
+        }
+        // The app stays open, so this is not a shutdown in progress.
+        state.shutting_down.store(false, Ordering::SeqCst);
+    }
+
+    Ok(())
+}
+
+/// Stops then restarts the sidecar, returning the newly bound port so the
+/// webview can re-navigate to it.
+#[tauri::command]
+fn restart_server(app: tauri::AppHandle) -> Result<u16, String> {
+    stop_server(app.clone())?;
+    start_server(app)
+}
+
 /// Returns true when LITESKILL_DEV=true — skip sidecar, connect to
 /// an already-running Phoenix dev server on port 4000 instead.
 fn dev_mode() -> bool {
@@ -222,9 +412,34 @@ fn run_production_mode() {
         .plugin(tauri_plugin_shell::init())
         .manage(AppState {
             sidecar_child: Mutex::new(None),
+            current_port: Mutex::new(None),
+            restart_attempts: AtomicU32::new(0),
+            user_generation: AtomicU32::new(0),
+            spawn_id: AtomicU32::new(0),
             shutting_down: AtomicBool::new(false),
+            log_writer: Mutex::new(None),
         })
+        .invoke_handler(tauri::generate_handler![
+            start_server,
+            stop_server,
+            restart_server
+        ])
         .setup(|app| {
+            // Graceful shutdown is otherwise only wired through Tauri's menu,
+            // window-close, and ExitRequested events, none of which fire if the
+            // app is terminated from a terminal, by the OS during logout, or via
+            // Ctrl-C — leaving the sidecar orphaned. kill_sidecar already guards
+            // against double-kill via `shutting_down`, so this composes cleanly
+            // with those other exit paths.
+            let signal_app_handle = app.handle().clone();
+            if let Err(e) = ctrlc::set_handler(move || {
+                println!("Termination signal received, shutting down sidecar...");
+                kill_sidecar(&signal_app_handle);
+                std::process::exit(0);
+            }) {
+                eprintln!("Failed to register termination signal handler: {e}");
+            }
+
             let port = match find_free_port() {
                 Ok(p) => p,
                 Err(e) => {
@@ -241,7 +456,7 @@ fn run_production_mode() {
             };
             println!("Using port {port} for Phoenix server");
 
-            if let Err(e) = start_server(app.handle(), port) {
+            if let Err(e) = spawn_sidecar(app.handle(), port) {
                 eprintln!("Failed to start sidecar: {e}");
                 rfd::MessageDialog::new()
                     .set_title("Liteskill - Startup Error")
@@ -266,6 +481,12 @@ fn run_production_mode() {
                 std::process::exit(1);
             }
 
+            if let Some(state) = app.try_state::<AppState>() {
+                if let Ok(mut guard) = state.current_port.lock() {
+                    *guard = Some(port);
+                }
+            }
+
             // Create the main window pointing at the dynamic port.
             // Window is NOT defined in tauri.conf.json — we build it here so the
             // URL reflects whichever port the OS assigned.
@@ -331,7 +552,7 @@ fn run_production_mode() {
         });
 }
 
-fn start_server(app: &tauri::AppHandle, port: u16) -> Result<(), String> {
+fn spawn_sidecar(app: &tauri::AppHandle, port: u16) -> Result<(), String> {
     let sidecar_command = app
         .shell()
         .sidecar("desktop")
@@ -346,6 +567,23 @@ fn start_server(app: &tauri::AppHandle, port: u16) -> Result<(), String> {
     let pid = child.pid();
     println!("Sidecar process started with PID: {pid}");
 
+    // Stamp this process with the generation current as of this spawn, so
+    // its exit-supervision task below can tell whether it's still the
+    // generation's active sidecar by the time it exits, or whether a
+    // manual stop/restart has already superseded it.
+    let generation = app
+        .try_state::<AppState>()
+        .map(|state| state.user_generation.load(Ordering::SeqCst))
+        .unwrap_or(0);
+    // Likewise, stamp the spawn_id current as of this spawn, so the exit-
+    // supervision task below can tell whether it's reporting on the sidecar
+    // AppState still considers current, or on one that's already been
+    // retired (and possibly replaced) by someone else.
+    let my_spawn_id = app
+        .try_state::<AppState>()
+        .map(|state| state.spawn_id.load(Ordering::SeqCst))
+        .unwrap_or(0);
+
     if let Some(state) = app.try_state::<AppState>() {
         if let Ok(mut guard) = state.sidecar_child.lock() {
             *guard = Some(SidecarProcess {
@@ -353,18 +591,52 @@ fn start_server(app: &tauri::AppHandle, port: u16) -> Result<(), String> {
                 pid: Some(pid),
             });
         }
+        ensure_log_writer(app, &state);
     }
 
+    let app_handle = app.clone();
     tauri::async_runtime::spawn(async move {
         while let Some(event) = rx.recv().await {
             match event {
                 CommandEvent::Stdout(line_bytes) => {
                     let line = String::from_utf8_lossy(&line_bytes);
                     println!("{line}");
+                    log_sidecar_line(&app_handle, "stdout", &line);
                 }
                 CommandEvent::Stderr(line_bytes) => {
                     let line = String::from_utf8_lossy(&line_bytes);
                     eprintln!("[sidecar stderr] {line}");
+                    log_sidecar_line(&app_handle, "stderr", &line);
+                }
+                CommandEvent::Terminated(payload) => {
+                    println!("Sidecar terminated: {payload:?}");
+
+                    let Some(state) = app_handle.try_state::<AppState>() else {
+                        break;
+                    };
+
+                    // Someone else (a manual kill or a prior supervised
+                    // restart) already retired this instance and its
+                    // cleanup already ran — don't clobber state that may
+                    // already belong to whatever replaced it.
+                    if state.spawn_id.load(Ordering::SeqCst) != my_spawn_id {
+                        break;
+                    }
+                    state.spawn_id.fetch_add(1, Ordering::SeqCst);
+
+                    if let Ok(mut guard) = state.sidecar_child.lock() {
+                        *guard = None;
+                    }
+                    if let Ok(mut guard) = state.current_port.lock() {
+                        *guard = None;
+                    }
+
+                    let should_restart = !state.shutting_down.load(Ordering::SeqCst)
+                        && state.user_generation.load(Ordering::SeqCst) == generation;
+                    if should_restart {
+                        supervise_restart(app_handle.clone(), port, generation);
+                    }
+                    break;
                 }
                 _ => {}
             }
@@ -374,6 +646,140 @@ fn start_server(app: &tauri::AppHandle, port: u16) -> Result<(), String> {
     Ok(())
 }
 
+/// Respawns a sidecar that exited unexpectedly, with capped exponential
+/// backoff: `min(base * 2^n, max)` delay before each attempt, where `n` is
+/// the number of consecutive failures so far. Gives up and surfaces an
+/// error dialog after `SUPERVISOR_MAX_RESTARTS` consecutive failures rather
+/// than retrying forever.
+fn supervise_restart(app: tauri::AppHandle, port: u16, generation: u32) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let Some(state) = app.try_state::<AppState>() else {
+                return;
+            };
+
+            // A manual stop/restart has taken over this sidecar's lineage
+            // since we started supervising it; stand down.
+            if state.user_generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+
+            let attempt = state.restart_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if attempt > SUPERVISOR_MAX_RESTARTS {
+                eprintln!("Sidecar crashed {attempt} times in a row, giving up");
+                rfd::MessageDialog::new()
+                    .set_title("Liteskill - Server Crashed")
+                    .set_description(
+                        "The application server keeps crashing and could not be restarted \
+                         automatically.\n\nPlease restart Liteskill.",
+                    )
+                    .set_level(rfd::MessageLevel::Error)
+                    .show();
+                return;
+            }
+
+            let delay = SUPERVISOR_BASE_DELAY
+                .saturating_mul(1 << (attempt - 1))
+                .min(SUPERVISOR_MAX_DELAY);
+            println!(
+                "Restarting sidecar in {delay:?} (attempt {attempt}/{SUPERVISOR_MAX_RESTARTS})..."
+            );
+            tokio::time::sleep(delay).await;
+
+            if state.shutting_down.load(Ordering::SeqCst)
+                || state.user_generation.load(Ordering::SeqCst) != generation
+            {
+                return;
+            }
+
+            let spawn_result = spawn_sidecar(&app, port);
+            if let Err(e) = spawn_result {
+                eprintln!("Failed to respawn sidecar: {e}");
+                continue;
+            }
+            // The spawn_id the sidecar just spawned was stamped with.
+            let spawned_id = state.spawn_id.load(Ordering::SeqCst);
+
+            // check_server_started is blocking (reqwest::blocking + thread::sleep),
+            // so run it off the async runtime's worker threads.
+            let ready = tauri::async_runtime::spawn_blocking(move || check_server_started(port))
+                .await
+                .unwrap_or_else(|e| Err(format!("readiness check task panicked: {e}")));
+
+            match ready {
+                Ok(()) => {
+                    state.restart_attempts.store(0, Ordering::SeqCst);
+                    println!("Sidecar restarted successfully on port {port}");
+                    let _ = app.emit("sidecar-restarted", port);
+                    return;
+                }
+                Err(e) => {
+                    eprintln!("Restarted sidecar did not become ready: {e}");
+                    // If the spawned process already exited, its own
+                    // Terminated handler has bumped spawn_id, cleaned up and
+                    // started any further restart; stand down.
+                    if state.spawn_id.load(Ordering::SeqCst) != spawned_id {
+                        return;
+                    }
+                    // Otherwise it is wedged, not dead, so no Terminated
+                    // event will arrive for it on its own — retire it
+                    // ourselves before looping around to try again.
+                    // retire_current_sidecar bumps spawn_id before
+                    // killing it, so its eventual Terminated event (once
+                    // the kill actually lands) sees it's been superseded
+                    // and stands down, instead of racing this loop into
+                    // spawning a duplicate restart.
+                    retire_current_sidecar(&state);
+                }
+            }
+        }
+    });
+}
+
+/// Opens the rotating sidecar log file on first use. A no-op once it's
+/// already open, so it's safe to call before every spawn (including
+/// supervised restarts).
+fn ensure_log_writer(app: &tauri::AppHandle, state: &AppState) {
+    let mut guard = match state.log_writer.lock() {
+        Ok(guard) => guard,
+        Err(_) => return,
+    };
+    if guard.is_some() {
+        return;
+    }
+
+    match app.path().app_log_dir() {
+        Ok(dir) => match RotatingLogWriter::open(&dir) {
+            Ok(writer) => *guard = Some(writer),
+            Err(e) => eprintln!("Failed to open sidecar log file: {e}"),
+        },
+        Err(e) => eprintln!("Failed to resolve app log dir: {e}"),
+    }
+}
+
+/// Persists a sidecar output line to the rotating log file and forwards it
+/// as a `sidecar-log` event.
+fn log_sidecar_line(app: &tauri::AppHandle, channel: &'static str, line: &str) {
+    let timestamp = chrono::Local::now().to_rfc3339();
+
+    if let Some(state) = app.try_state::<AppState>() {
+        if let Ok(mut guard) = state.log_writer.lock() {
+            if let Some(writer) = guard.as_mut() {
+                writer.write_line(&format!("[{timestamp}] [{channel}] {line}"));
+            }
+        }
+    }
+
+    let _ = app.emit(
+        "sidecar-log",
+        SidecarLogEvent {
+            channel,
+            line: line.to_string(),
+            timestamp,
+        },
+    );
+}
+
 /// Tries each port in `PREFERRED_PORTS` in order, then falls back to an
 /// OS-assigned ephemeral port.
 fn find_free_port() -> Result<u16, String> {
@@ -383,6 +789,19 @@ fn find_free_port() -> Result<u16, String> {
             return Ok(port);
         }
         println!("Port {port} is in use, trying next...");
+
+        if let Some(pid) = find_pid_on_port(port) {
+            if is_stale_sidecar_process(pid) && should_reclaim_port(port, pid) {
+                println!("Reclaiming port {port} from stale sidecar (PID {pid})...");
+                kill_process_by_pid(pid);
+                std::thread::sleep(Duration::from_millis(200));
+                if let Ok(listener) = TcpListener::bind(("127.0.0.1", port)) {
+                    drop(listener);
+                    return Ok(port);
+                }
+                println!("Port {port} still unavailable after reclaim attempt");
+            }
+        }
     }
     println!("All preferred ports in use, finding an ephemeral port...");
     let listener = TcpListener::bind("127.0.0.1:0")
@@ -394,21 +813,146 @@ fn find_free_port() -> Result<u16, String> {
     Ok(port)
 }
 
-/// Polls TCP connection until the Phoenix server is reachable on the given port.
+/// Looks up the PID bound to a TCP port on the current host, if any.
+fn find_pid_on_port(port: u16) -> Option<u32> {
+    use netstat2::{iterate_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+
+    // The sidecar can just as easily bind the preferred port on IPv6
+    // loopback (`::1`) as on IPv4, so check both — otherwise an orphan
+    // bound on IPv6 is never found and reclaim silently never triggers.
+    let flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+    let sockets = iterate_sockets_info(flags, ProtocolFlags::TCP).ok()?;
+    for info in sockets.flatten() {
+        if let ProtocolSocketInfo::Tcp(tcp) = &info.protocol_socket_info {
+            if tcp.local_port == port {
+                return info.associated_pids.first().copied();
+            }
+        }
+    }
+    None
+}
+
+/// The binary name Tauri spawns the Burrito sidecar as (see `spawn_sidecar`'s
+/// `.sidecar("desktop")`), possibly suffixed with a target triple.
+const SIDECAR_BIN_NAME: &str = "desktop";
+
+/// Returns true only if the process name is an exact or target-triple-suffixed
+/// match for the sidecar binary, e.g. `desktop` or `desktop-x86_64-pc-windows-msvc`
+/// — not merely a name that *contains* it, which would also catch unrelated
+/// processes like `xdg-desktop-portal`.
+fn matches_sidecar_bin_name(name: &str) -> bool {
+    name == SIDECAR_BIN_NAME || name.starts_with(&format!("{SIDECAR_BIN_NAME}-"))
+}
+
+/// Returns true if `pid` looks like a sidecar orphaned by a previous
+/// crashed Liteskill session, i.e. a `desktop` (Burrito) process — never
+/// an unrelated process that merely happens to hold the port.
+fn is_stale_sidecar_process(pid: u32) -> bool {
+    let mut system = sysinfo::System::new();
+    system.refresh_process(sysinfo::Pid::from_u32(pid));
+    let Some(process) = system.process(sysinfo::Pid::from_u32(pid)) else {
+        return false;
+    };
+
+    if !matches_sidecar_bin_name(&process.name().to_string_lossy()) {
+        return false;
+    }
+
+    // Corroborate with the executable path when it's readable. The name
+    // match is already anchored, but this catches the rarer case of an
+    // unrelated binary that was literally renamed to collide with it.
+    // Permission to read another process's exe path can be denied, in
+    // which case we fall back to the name match alone.
+    match process
+        .exe()
+        .and_then(|path| path.file_stem())
+        .map(|stem| stem.to_string_lossy().into_owned())
+    {
+        Some(stem) => matches_sidecar_bin_name(&stem),
+        None => true,
+    }
+}
+
+/// Confirms with the user (or, for headless runs, `LITESKILL_RECLAIM_PORT`)
+/// before killing a process that merely looks stale — unrelated processes
+/// should never be killed without explicit consent.
+fn should_reclaim_port(port: u16, pid: u32) -> bool {
+    if std::env::var("LITESKILL_RECLAIM_PORT").unwrap_or_default() == "true" {
+        return true;
+    }
+
+    rfd::MessageDialog::new()
+        .set_title("Liteskill - Port In Use")
+        .set_description(&format!(
+            "Port {port} appears to be held by a leftover Liteskill server \
+             (PID {pid}) from a previous session.\n\n\
+             Terminate it and reclaim the port?"
+        ))
+        .set_level(rfd::MessageLevel::Warning)
+        .set_buttons(rfd::MessageButtons::YesNo)
+        .show()
+        == rfd::MessageDialogResult::Yes
+}
+
+fn kill_process_by_pid(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        std::process::Command::new("kill")
+            .args(["-TERM", &pid.to_string()])
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(false)
+    }
+    #[cfg(windows)]
+    {
+        std::process::Command::new("taskkill")
+            .args(["/F", "/PID", &pid.to_string()])
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(false)
+    }
+}
+
+/// Returns true when LITESKILL_SKIP_SERVER_CHECK=true — skip the readiness
+/// wait entirely, for fast local iteration against a server you know is up.
+fn skip_server_check() -> bool {
+    std::env::var("LITESKILL_SKIP_SERVER_CHECK").unwrap_or_default() == "true"
+}
+
+/// Polls an HTTP health check until the Phoenix server is actually able to
+/// serve requests, not just until its listener socket is bound. A bare TCP
+/// connect succeeds the instant Phoenix binds the socket, well before the
+/// app is done booting, which used to send the webview into a
+/// connection-refused or half-initialized page.
+///
+/// Any HTTP response counts as ready, including a 404 — we don't know the
+/// app's route table here, and requiring a 2xx/3xx on `/` specifically
+/// would fail startup for any router that doesn't happen to map the root
+/// path to something that redirects or succeeds.
 /// Returns an error if the server doesn't start within `SERVER_READY_TIMEOUT`.
 fn check_server_started(port: u16) -> Result<(), String> {
-    let addr = format!("localhost:{port}");
-    println!("Waiting for Phoenix server to start on {addr}...");
+    if skip_server_check() {
+        println!("LITESKILL_SKIP_SERVER_CHECK=true, skipping readiness check");
+        return Ok(());
+    }
+
+    let url = format!("http://localhost:{port}/");
+    println!("Waiting for Phoenix server to become ready at {url}...");
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(SERVER_READY_POLL)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {e}"))?;
 
     let start = std::time::Instant::now();
     loop {
-        if std::net::TcpStream::connect(&addr).is_ok() {
+        if client.get(&url).send().is_ok() {
             println!("Phoenix server is ready");
             return Ok(());
         }
         if start.elapsed() >= SERVER_READY_TIMEOUT {
             return Err(format!(
-                "Server did not become reachable at {addr} within {}s",
+                "Server did not become reachable at {url} within {}s",
                 SERVER_READY_TIMEOUT.as_secs()
             ));
         }